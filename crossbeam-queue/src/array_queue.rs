@@ -0,0 +1,808 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::panic::{RefUnwindSafe, UnwindSafe};
+
+use crossbeam_utils::{Backoff, CachePadded};
+
+use crate::primitive::cell::UnsafeCell;
+use crate::primitive::sync::atomic::{self, AtomicUsize, Ordering};
+
+/// A slot in a queue.
+struct Slot<T> {
+    /// The current stamp.
+    ///
+    /// If the stamp equals the tail, this node will be next written to. If it equals head + 1,
+    /// this node will be next read from.
+    stamp: AtomicUsize,
+
+    /// The value in this slot.
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// A bounded multi-producer multi-consumer queue.
+///
+/// This queue allocates a fixed-capacity buffer on construction, which is used to store pushed
+/// elements. The queue cannot hold more elements than the buffer allows. Attempting to push an
+/// element into a full queue will fail. Alternatively, [`force_push`] makes it possible for this
+/// queue to be used as a ring-buffer. Having a buffer allocated upfront makes this queue a bit
+/// faster than [`SegQueue`].
+///
+/// [`force_push`]: ArrayQueue::force_push
+/// [`SegQueue`]: super::SegQueue
+///
+/// # Examples
+///
+/// ```
+/// use crossbeam_queue::ArrayQueue;
+///
+/// let q = ArrayQueue::new(2);
+///
+/// assert_eq!(q.push('a'), Ok(()));
+/// assert_eq!(q.push('b'), Ok(()));
+/// assert_eq!(q.push('c'), Err('c'));
+/// assert_eq!(q.pop(), Some('a'));
+/// ```
+pub struct ArrayQueue<T> {
+    /// The head of the queue.
+    ///
+    /// This value is a "stamp" consisting of an index into the buffer and a lap, but packed into a
+    /// single `usize`. The lower bits represent the index, while the upper bits represent the lap.
+    head: CachePadded<AtomicUsize>,
+
+    /// The tail of the queue.
+    ///
+    /// This value is a "stamp" consisting of an index into the buffer and a lap, but packed into a
+    /// single `usize`. The lower bits represent the index, while the upper bits represent the lap.
+    tail: CachePadded<AtomicUsize>,
+
+    /// The buffer holding slots.
+    buffer: Box<[Slot<T>]>,
+
+    /// The queue capacity.
+    cap: usize,
+
+    /// A stamp with the value of `{ lap: 1, index: 0 }`.
+    one_lap: usize,
+
+    /// Indicates that dropping an `ArrayQueue<T>` may drop elements of type `T`.
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Sync for ArrayQueue<T> {}
+unsafe impl<T: Send> Send for ArrayQueue<T> {}
+
+impl<T> UnwindSafe for ArrayQueue<T> {}
+impl<T> RefUnwindSafe for ArrayQueue<T> {}
+
+impl<T> ArrayQueue<T> {
+    /// Creates a new bounded queue with the given capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::<i32>::new(100);
+    /// ```
+    pub fn new(cap: usize) -> ArrayQueue<T> {
+        assert!(cap > 0, "capacity must be non-zero");
+
+        // Head is initialized to `{ lap: 0, index: 0 }`.
+        // Tail is initialized to `{ lap: 0, index: 0 }`.
+        let head = 0;
+        let tail = 0;
+
+        // Allocate a buffer of `cap` slots initialized with stamps.
+        let buffer: Box<[Slot<T>]> = (0..cap)
+            .map(|i| {
+                // Set the stamp to `{ lap: 0, index: i }`.
+                Slot {
+                    stamp: AtomicUsize::new(i),
+                    value: UnsafeCell::new(MaybeUninit::uninit()),
+                }
+            })
+            .collect();
+
+        // One lap is the smallest power of two greater than `cap`.
+        let one_lap = (cap + 1).next_power_of_two();
+
+        ArrayQueue {
+            buffer,
+            cap,
+            one_lap,
+            head: CachePadded::new(AtomicUsize::new(head)),
+            tail: CachePadded::new(AtomicUsize::new(tail)),
+            _marker: PhantomData,
+        }
+    }
+
+    fn push_or_else<F>(&self, mut value: T, f: F) -> Result<(), T>
+    where
+        F: Fn(T, usize, usize, &Slot<T>) -> Result<T, T>,
+    {
+        let backoff = Backoff::new();
+        let mut tail = self.tail.load(Ordering::Relaxed);
+
+        loop {
+            // Deconstruct the tail.
+            let index = tail & (self.one_lap - 1);
+            let lap = tail & !(self.one_lap - 1);
+
+            let new_tail = if index + 1 < self.cap {
+                // Same lap, incremented index.
+                // Set to `{ lap: lap, index: index + 1 }`.
+                tail + 1
+            } else {
+                // One lap forward, index wraps around to zero.
+                // Set to `{ lap: lap.wrapping_add(1), index: 0 }`.
+                lap.wrapping_add(self.one_lap)
+            };
+
+            // Inspect the corresponding slot.
+            // SAFETY: This is safe because `index` will always be within the bounds of the buffer.
+            let slot = unsafe { self.buffer.get_unchecked(index) };
+            let stamp = slot.stamp.load(Ordering::Acquire);
+
+            // If the tail and the stamp match, we may attempt to push.
+            if tail == stamp {
+                // Try moving the tail.
+                match self.tail.compare_exchange_weak(
+                    tail,
+                    new_tail,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // Write the value into the slot and update the stamp.
+                        // SAFETY: This is safe because we own the slot.
+                        unsafe {
+                            slot.value.with_mut(|slot| slot.write(MaybeUninit::new(value)));
+                        }
+                        slot.stamp.store(tail + 1, Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(t) => {
+                        tail = t;
+                        backoff.spin();
+                    }
+                }
+            } else if stamp.wrapping_add(self.one_lap) == tail + 1 {
+                atomic::fence(Ordering::SeqCst);
+                value = f(value, tail, new_tail, slot)?;
+                backoff.spin();
+                tail = self.tail.load(Ordering::Relaxed);
+            } else {
+                // Snooze because we need to wait for the stamp to get updated.
+                backoff.snooze();
+                tail = self.tail.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Attempts to push an element into the queue.
+    ///
+    /// If the queue is full, the element is returned back as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(1);
+    ///
+    /// assert_eq!(q.push(10), Ok(()));
+    /// assert_eq!(q.push(20), Err(20));
+    /// ```
+    pub fn push(&self, value: T) -> Result<(), T> {
+        self.push_or_else(value, |v, _, _, _| Err(v))
+    }
+
+    /// Pushes an element into the queue, replacing the oldest element if necessary.
+    ///
+    /// If the queue is full, the oldest element is replaced and returned,
+    /// otherwise `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(2);
+    ///
+    /// assert_eq!(q.force_push(10), None);
+    /// assert_eq!(q.force_push(20), None);
+    /// assert_eq!(q.force_push(30), Some(10));
+    /// assert_eq!(q.pop(), Some(20));
+    /// ```
+    pub fn force_push(&self, value: T) -> Option<T> {
+        self.push_or_else(value, |v, tail, new_tail, slot| {
+            let head = tail.wrapping_sub(self.one_lap);
+            let new_head = new_tail.wrapping_sub(self.one_lap);
+
+            // Try to move the head.
+            if self
+                .head
+                .compare_exchange_weak(head, new_head, Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok()
+            {
+                // Move the tail.
+                self.tail.store(new_tail, Ordering::SeqCst);
+
+                // Swap the previous value.
+                // SAFETY: This is safe because we own the slot.
+                let old = unsafe {
+                    slot.value
+                        .with_mut(|slot| slot.replace(MaybeUninit::new(v)).assume_init())
+                };
+
+                // Update the stamp.
+                slot.stamp.store(tail + 1, Ordering::Release);
+
+                Err(old)
+            } else {
+                Ok(v)
+            }
+        })
+        .err()
+    }
+
+    /// Attempts to pop an element from the queue.
+    ///
+    /// If the queue is empty, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(1);
+    /// assert_eq!(q.push(10), Ok(()));
+    ///
+    /// assert_eq!(q.pop(), Some(10));
+    /// assert!(q.pop().is_none());
+    /// ```
+    pub fn pop(&self) -> Option<T> {
+        let backoff = Backoff::new();
+        let mut head = self.head.load(Ordering::Relaxed);
+
+        loop {
+            // Deconstruct the head.
+            let index = head & (self.one_lap - 1);
+            let lap = head & !(self.one_lap - 1);
+
+            // Inspect the corresponding slot.
+            // SAFETY: This is safe because `index` will always be within the bounds of the buffer.
+            let slot = unsafe { self.buffer.get_unchecked(index) };
+            let stamp = slot.stamp.load(Ordering::Acquire);
+
+            // If the stamp is ahead of the head by 1, we may attempt to pop.
+            if head + 1 == stamp {
+                let new = if index + 1 < self.cap {
+                    // Same lap, incremented index.
+                    // Set to `{ lap: lap, index: index + 1 }`.
+                    head + 1
+                } else {
+                    // One lap forward, index wraps around to zero.
+                    // Set to `{ lap: lap.wrapping_add(1), index: 0 }`.
+                    lap.wrapping_add(self.one_lap)
+                };
+
+                // Try moving the head.
+                match self.head.compare_exchange_weak(
+                    head,
+                    new,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // Read the value from the slot and update the stamp.
+                        // SAFETY: This is safe because we own the slot.
+                        let value = unsafe {
+                            slot.value.with_mut(|slot| slot.read().assume_init())
+                        };
+                        slot.stamp
+                            .store(head.wrapping_add(self.one_lap), Ordering::Release);
+                        return Some(value);
+                    }
+                    Err(h) => {
+                        head = h;
+                        backoff.spin();
+                    }
+                }
+            } else if stamp == head {
+                atomic::fence(Ordering::SeqCst);
+                let tail = self.tail.load(Ordering::Relaxed);
+
+                // If the tail equals the head, that means the queue is empty.
+                if tail == head {
+                    return None;
+                }
+
+                backoff.spin();
+                head = self.head.load(Ordering::Relaxed);
+            } else {
+                // Snooze because we need to wait for the stamp to get updated.
+                backoff.snooze();
+                head = self.head.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Returns the capacity of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::<i32>::new(100);
+    ///
+    /// assert_eq!(q.capacity(), 100);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Returns `true` if the queue is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(100);
+    ///
+    /// assert!(q.is_empty());
+    /// q.push(1).unwrap();
+    /// assert!(!q.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        let head = self.head.load(Ordering::SeqCst);
+        let tail = self.tail.load(Ordering::SeqCst);
+
+        // Is the tail equal to the head?
+        //
+        // Note: If the head changes just before we load the tail, that means there was a moment
+        // when the queue was not empty, so it is safe to just return `false`.
+        tail == head
+    }
+
+    /// Returns `true` if the queue is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(1);
+    ///
+    /// assert!(!q.is_full());
+    /// q.push(1).unwrap();
+    /// assert!(q.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        let tail = self.tail.load(Ordering::SeqCst);
+        let head = self.head.load(Ordering::SeqCst);
+
+        // Is the head lagging one lap behind tail?
+        //
+        // Note: If the tail changes just before we load the head, that means there was a moment
+        // when the queue was not full, so it is safe to just return `false`.
+        head.wrapping_add(self.one_lap) == tail
+    }
+
+    /// Returns the number of elements in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(100);
+    /// assert_eq!(q.len(), 0);
+    ///
+    /// q.push(10).unwrap();
+    /// assert_eq!(q.len(), 1);
+    ///
+    /// q.push(20).unwrap();
+    /// assert_eq!(q.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        loop {
+            // Load the tail, then load the head.
+            let tail = self.tail.load(Ordering::SeqCst);
+            let head = self.head.load(Ordering::SeqCst);
+
+            // If the tail didn't change, we've got consistent values to work with.
+            if self.tail.load(Ordering::SeqCst) == tail {
+                let hix = head & (self.one_lap - 1);
+                let tix = tail & (self.one_lap - 1);
+
+                return if hix < tix {
+                    tix - hix
+                } else if hix > tix {
+                    self.cap - hix + tix
+                } else if tail == head {
+                    0
+                } else {
+                    self.cap
+                };
+            }
+        }
+    }
+
+    /// Returns an iterator that pops elements off the queue until it is observed empty.
+    ///
+    /// The iterator borrows the queue, so other threads may continue to push and pop concurrently;
+    /// iteration simply stops the first time [`pop`] returns `None`. This is a convenient
+    /// replacement for the common `while let Some(x) = q.pop()` drain loop.
+    ///
+    /// [`pop`]: ArrayQueue::pop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(5);
+    /// for i in 0..5 {
+    ///     q.push(i).unwrap();
+    /// }
+    ///
+    /// assert_eq!(q.pop_iter().sum::<i32>(), 10);
+    /// assert!(q.is_empty());
+    /// ```
+    pub fn pop_iter(&self) -> impl Iterator<Item = T> + '_ {
+        core::iter::from_fn(move || self.pop())
+    }
+
+    /// Pushes a batch of elements into the queue, draining them off the front of `items`.
+    ///
+    /// Rather than paying for one `compare_exchange` per element, this reserves a contiguous run of
+    /// slots with a single `compare_exchange` on the tail and then fills them. The run is bounded
+    /// by the number of elements in `items`, by the number of free slots, and by the distance to
+    /// the end of the buffer, so a single call never wraps around; callers wanting to push more
+    /// should call again. The successfully pushed elements are removed from `items` and the number
+    /// moved is returned.
+    ///
+    /// Each slot's stamp is published individually after the bulk claim, so a concurrent
+    /// single-element [`pop`] can start draining the run before it has been fully written.
+    ///
+    /// [`pop`]: ArrayQueue::pop
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(4);
+    /// let mut items = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// assert_eq!(q.push_slice(&mut items), 4);
+    /// assert_eq!(items, vec![5, 6]);
+    /// ```
+    pub fn push_slice(&self, items: &mut Vec<T>) -> usize {
+        if items.is_empty() {
+            return 0;
+        }
+
+        let backoff = Backoff::new();
+        let mut tail = self.tail.load(Ordering::Relaxed);
+
+        loop {
+            // Deconstruct the tail.
+            let index = tail & (self.one_lap - 1);
+            let lap = tail & !(self.one_lap - 1);
+
+            // Inspect the first slot of the run.
+            // SAFETY: `index` is always within the bounds of the buffer.
+            let slot = unsafe { self.buffer.get_unchecked(index) };
+            let stamp = slot.stamp.load(Ordering::Acquire);
+
+            if tail == stamp {
+                // Figure out how many elements can be claimed in one run: bounded by the request,
+                // by the free space, and by the distance to the end of the buffer (a run must not
+                // wrap around, so its stamps stay contiguous).
+                let head = self.head.load(Ordering::Acquire);
+                let hix = head & (self.one_lap - 1);
+                let len = if hix < index {
+                    index - hix
+                } else if hix > index {
+                    self.cap - hix + index
+                } else if head == tail {
+                    0
+                } else {
+                    self.cap
+                };
+                let to_end = self.cap - index;
+                // `slot[index]` is writable (its stamp matched the tail), so at least one slot is
+                // free right now. A stale `head` snapshot can inflate `len` to `cap` and make
+                // `cap - len` momentarily 0, so clamp to at least 1: a transient "full" reading
+                // must never make `push_slice` return 0 (which a caller could mistake for a
+                // genuinely full queue) while it can still make progress.
+                let n = items.len().min(self.cap - len).min(to_end).max(1);
+
+                let new_tail = if index + n < self.cap {
+                    tail + n
+                } else {
+                    lap.wrapping_add(self.one_lap)
+                };
+
+                // Reserve the whole run with a single CAS.
+                match self.tail.compare_exchange_weak(
+                    tail,
+                    new_tail,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        for (i, value) in items.drain(0..n).enumerate() {
+                            // SAFETY: `index + i < cap`, so the slot is within bounds.
+                            let slot = unsafe { self.buffer.get_unchecked(index + i) };
+                            let stamp = tail + i;
+
+                            // A lagging consumer may still be reading the slot; wait for it.
+                            while slot.stamp.load(Ordering::Acquire) != stamp {
+                                backoff.snooze();
+                            }
+
+                            // SAFETY: we own the slot.
+                            unsafe {
+                                slot.value.with_mut(|slot| slot.write(MaybeUninit::new(value)));
+                            }
+                            slot.stamp.store(stamp + 1, Ordering::Release);
+                        }
+                        return n;
+                    }
+                    Err(t) => {
+                        tail = t;
+                        backoff.spin();
+                    }
+                }
+            } else if stamp.wrapping_add(self.one_lap) == tail + 1 {
+                // The queue is full.
+                atomic::fence(Ordering::SeqCst);
+                return 0;
+            } else {
+                // Snooze because we need to wait for the stamp to get updated.
+                backoff.snooze();
+                tail = self.tail.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Pops a batch of elements off the queue into `out`, returning the number written.
+    ///
+    /// This claims a contiguous run of slots with a single `compare_exchange` on the head, then
+    /// moves the values out into the leading slots of `out`. The run is bounded by the length of
+    /// `out`, by the number of queued elements, and by the distance to the end of the buffer, so a
+    /// single call never wraps around; callers wanting more should call again. The first `n`
+    /// entries of `out` are initialized, where `n` is the returned count.
+    ///
+    /// Each slot's stamp is published individually after the bulk claim, so a concurrent
+    /// single-element [`push`] can start refilling the run before it has been fully drained.
+    ///
+    /// [`push`]: ArrayQueue::push
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use crossbeam_queue::ArrayQueue;
+    ///
+    /// let q = ArrayQueue::new(4);
+    /// for i in 0..3 {
+    ///     q.push(i).unwrap();
+    /// }
+    ///
+    /// let mut out = [MaybeUninit::uninit(); 4];
+    /// let n = q.pop_slice(&mut out);
+    /// assert_eq!(n, 3);
+    /// let got = out[..n]
+    ///     .iter()
+    ///     .map(|slot| unsafe { slot.assume_init() })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(got, vec![0, 1, 2]);
+    /// ```
+    pub fn pop_slice(&self, out: &mut [MaybeUninit<T>]) -> usize {
+        if out.is_empty() {
+            return 0;
+        }
+
+        let backoff = Backoff::new();
+        let mut head = self.head.load(Ordering::Relaxed);
+
+        loop {
+            // Deconstruct the head.
+            let index = head & (self.one_lap - 1);
+            let lap = head & !(self.one_lap - 1);
+
+            // Inspect the first slot of the run.
+            // SAFETY: `index` is always within the bounds of the buffer.
+            let slot = unsafe { self.buffer.get_unchecked(index) };
+            let stamp = slot.stamp.load(Ordering::Acquire);
+
+            if head + 1 == stamp {
+                // Figure out how many elements can be claimed in one run: bounded by the output
+                // slice, by the queued elements, and by the distance to the end of the buffer.
+                let tail = self.tail.load(Ordering::Acquire);
+                let tix = tail & (self.one_lap - 1);
+                let len = if index < tix {
+                    tix - index
+                } else if index > tix {
+                    self.cap - index + tix
+                } else if tail == head {
+                    0
+                } else {
+                    self.cap
+                };
+                let to_end = self.cap - index;
+                // `slot[index]` is readable (its stamp is one ahead of the head), so at least one
+                // element is available. A stale `tail` snapshot can deflate `len` to 0, so clamp
+                // to at least 1: a transient "empty" reading must never make `pop_slice` return 0
+                // (which a caller could mistake for a genuinely empty queue) while progress is
+                // possible.
+                let n = out.len().min(len).min(to_end).max(1);
+
+                let new_head = if index + n < self.cap {
+                    head + n
+                } else {
+                    lap.wrapping_add(self.one_lap)
+                };
+
+                // Claim the whole run with a single CAS.
+                match self.head.compare_exchange_weak(
+                    head,
+                    new_head,
+                    Ordering::SeqCst,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        for (i, out) in out[..n].iter_mut().enumerate() {
+                            // SAFETY: `index + i < cap`, so the slot is within bounds.
+                            let slot = unsafe { self.buffer.get_unchecked(index + i) };
+                            let stamp = head + i + 1;
+
+                            // A lagging producer may still be writing the slot; wait for it.
+                            while slot.stamp.load(Ordering::Acquire) != stamp {
+                                backoff.snooze();
+                            }
+
+                            // SAFETY: we own the slot.
+                            let value =
+                                unsafe { slot.value.with_mut(|slot| slot.read().assume_init()) };
+                            out.write(value);
+                            slot.stamp.store(
+                                (head + i).wrapping_add(self.one_lap),
+                                Ordering::Release,
+                            );
+                        }
+                        return n;
+                    }
+                    Err(h) => {
+                        head = h;
+                        backoff.spin();
+                    }
+                }
+            } else if stamp == head {
+                atomic::fence(Ordering::SeqCst);
+
+                // If the tail equals the head, that means the queue is empty.
+                if self.tail.load(Ordering::Relaxed) == head {
+                    return 0;
+                }
+
+                backoff.spin();
+                head = self.head.load(Ordering::Relaxed);
+            } else {
+                // Snooze because we need to wait for the stamp to get updated.
+                backoff.snooze();
+                head = self.head.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl<T> Drop for ArrayQueue<T> {
+    fn drop(&mut self) {
+        if core::mem::needs_drop::<T>() {
+            // Get the index of the head.
+            let head = self.head.with_mut(|&mut head| head);
+            let tail = self.tail.with_mut(|&mut tail| tail);
+
+            let hix = head & (self.one_lap - 1);
+            let tix = tail & (self.one_lap - 1);
+
+            let len = if hix < tix {
+                tix - hix
+            } else if hix > tix {
+                self.cap - hix + tix
+            } else if tail == head {
+                0
+            } else {
+                self.cap
+            };
+
+            // Loop over all slots that hold a value and drop them.
+            for i in 0..len {
+                // Compute the index of the next slot holding a value.
+                let index = if hix + i < self.cap {
+                    hix + i
+                } else {
+                    hix + i - self.cap
+                };
+
+                // SAFETY: we own the queue, so dropping the value in the slot is safe.
+                unsafe {
+                    self.buffer[index]
+                        .value
+                        .with_mut(|slot| (*slot).as_mut_ptr().drop_in_place());
+                }
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for ArrayQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("ArrayQueue { .. }")
+    }
+}
+
+impl<T> IntoIterator for ArrayQueue<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { value: self }
+    }
+}
+
+/// An owning iterator over the elements of an [`ArrayQueue`].
+///
+/// This `struct` is created by calling `into_iter` on an [`ArrayQueue`]. Any elements not consumed
+/// by the iterator are dropped when it is.
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    value: ArrayQueue<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = &mut self.value;
+        let head = value.head.with_mut(|&mut head| head);
+        let tail = value.tail.with_mut(|&mut tail| tail);
+        if head == tail {
+            // The queue is empty.
+            None
+        } else {
+            // We have exclusive access, so we can move the value out of the slot directly and then
+            // bump the head past it; `ArrayQueue`'s own `Drop` uses the head/tail stamps, so the
+            // remaining elements are still accounted for.
+            let index = head & (value.one_lap - 1);
+            let lap = head & !(value.one_lap - 1);
+            let item = unsafe {
+                value.buffer[index]
+                    .value
+                    .with_mut(|slot| slot.read().assume_init())
+            };
+            let new = if index + 1 < value.cap {
+                head + 1
+            } else {
+                lap.wrapping_add(value.one_lap)
+            };
+            value.head.with_mut(|head| *head = new);
+            Some(item)
+        }
+    }
+}
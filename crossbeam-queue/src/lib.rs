@@ -4,6 +4,7 @@
 //!
 //! * [`ArrayQueue`], a bounded MPMC queue that allocates a fixed-capacity buffer on construction.
 //! * [`SegQueue`], an unbounded MPMC queue that allocates small buffers, segments, on demand.
+//! * [`SpscQueue`], a bounded single-producer single-consumer queue backed by a ring buffer.
 
 #![no_std]
 #![doc(test(
@@ -15,20 +16,41 @@
 ))]
 #![warn(missing_docs, unsafe_op_in_unsafe_fn)]
 
-#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg(all(
+    feature = "alloc",
+    any(target_has_atomic = "ptr", feature = "portable-atomic")
+))]
 extern crate alloc;
 #[cfg(crossbeam_loom)]
 extern crate loom_crate as loom;
 #[cfg(feature = "std")]
 extern crate std;
 
-#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg(all(
+    feature = "alloc",
+    any(target_has_atomic = "ptr", feature = "portable-atomic")
+))]
 mod array_queue;
-#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg(all(
+    feature = "alloc",
+    any(target_has_atomic = "ptr", feature = "portable-atomic")
+))]
 mod seg_queue;
+#[cfg(all(
+    feature = "alloc",
+    any(target_has_atomic = "ptr", feature = "portable-atomic")
+))]
+mod spsc_queue;
 
-#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
-pub use crate::{array_queue::ArrayQueue, seg_queue::SegQueue};
+#[cfg(all(
+    feature = "alloc",
+    any(target_has_atomic = "ptr", feature = "portable-atomic")
+))]
+pub use crate::{
+    array_queue::ArrayQueue,
+    seg_queue::SegQueue,
+    spsc_queue::{Consumer, Producer, SpscQueue},
+};
 
 #[cfg(crossbeam_loom)]
 #[allow(unused_imports, dead_code)]
@@ -53,6 +75,7 @@ mod primitive {
 }
 #[cfg(target_has_atomic = "ptr")]
 #[cfg(not(crossbeam_loom))]
+#[cfg(not(feature = "portable-atomic"))]
 #[allow(unused_imports, dead_code)]
 mod primitive {
     pub(crate) mod cell {
@@ -163,3 +186,112 @@ mod primitive {
         }
     }
 }
+#[cfg(feature = "portable-atomic")]
+#[cfg(not(crossbeam_loom))]
+#[allow(unused_imports, dead_code)]
+mod primitive {
+    pub(crate) mod cell {
+        #[derive(Debug)]
+        #[repr(transparent)]
+        pub(crate) struct UnsafeCell<T>(::core::cell::UnsafeCell<T>);
+
+        // Mirrors the non-loom `UnsafeCell` wrapper above; the cell type is orthogonal to which
+        // crate the atomics come from, so this is identical to the `core` arm.
+        impl<T> UnsafeCell<T> {
+            #[inline]
+            pub(crate) const fn new(data: T) -> Self {
+                Self(::core::cell::UnsafeCell::new(data))
+            }
+
+            #[inline]
+            pub(crate) fn with<R>(&self, f: impl FnOnce(*const T) -> R) -> R {
+                f(self.0.get())
+            }
+
+            #[inline]
+            pub(crate) fn with_mut<R>(&self, f: impl FnOnce(*mut T) -> R) -> R {
+                f(self.0.get())
+            }
+        }
+    }
+    pub(crate) mod sync {
+        pub(crate) mod atomic {
+            pub(crate) use portable_atomic::{compiler_fence, fence, Ordering};
+
+            pub(crate) struct AtomicPtr<T>(::portable_atomic::AtomicPtr<T>);
+
+            impl<T> AtomicPtr<T> {
+                pub(crate) const fn new(x: *mut T) -> Self {
+                    Self(::portable_atomic::AtomicPtr::new(x))
+                }
+
+                pub(crate) fn load(&self, order: Ordering) -> *mut T {
+                    self.0.load(order)
+                }
+
+                pub(crate) fn store(&self, value: *mut T, order: Ordering) {
+                    self.0.store(value, order);
+                }
+
+                pub(crate) fn compare_exchange(
+                    &self,
+                    current: *mut T,
+                    new: *mut T,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<*mut T, *mut T> {
+                    self.0.compare_exchange(current, new, success, failure)
+                }
+
+                pub(crate) fn compare_exchange_weak(
+                    &self,
+                    current: *mut T,
+                    new: *mut T,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<*mut T, *mut T> {
+                    self.0.compare_exchange_weak(current, new, success, failure)
+                }
+
+                pub(crate) fn with_mut<R>(&mut self, f: impl FnOnce(&mut *mut T) -> R) -> R {
+                    f(self.0.get_mut())
+                }
+            }
+
+            pub(crate) struct AtomicUsize(::portable_atomic::AtomicUsize);
+
+            // Similar to UnsafeCell, AtomicUsize has a slightly different API.
+            impl AtomicUsize {
+                pub(crate) const fn new(x: usize) -> Self {
+                    Self(::portable_atomic::AtomicUsize::new(x))
+                }
+
+                pub(crate) fn load(&self, order: Ordering) -> usize {
+                    self.0.load(order)
+                }
+
+                pub(crate) fn store(&self, value: usize, order: Ordering) {
+                    self.0.store(value, order);
+                }
+
+                pub(crate) fn compare_exchange_weak(
+                    &self,
+                    current: usize,
+                    new: usize,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<usize, usize> {
+                    self.0.compare_exchange_weak(current, new, success, failure)
+                }
+
+                pub(crate) fn fetch_or(&self, value: usize, order: Ordering) -> usize {
+                    self.0.fetch_or(value, order)
+                }
+
+                pub(crate) fn with_mut<T>(&mut self, f: impl FnOnce(&mut usize) -> T) -> T {
+                    f(self.0.get_mut())
+                }
+            }
+        }
+    }
+}
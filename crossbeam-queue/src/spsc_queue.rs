@@ -0,0 +1,345 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::panic::{RefUnwindSafe, UnwindSafe};
+
+use crossbeam_utils::CachePadded;
+
+use crate::primitive::cell::UnsafeCell;
+use crate::primitive::sync::atomic::{AtomicUsize, Ordering};
+
+/// The shared ring buffer backing a single-producer single-consumer queue.
+///
+/// Unlike [`ArrayQueue`], which supports any number of producers and consumers and therefore pays
+/// for a `compare_exchange` loop on every operation, `SpscQueue` exploits the restriction that
+/// exactly one thread pushes and exactly one thread pops. This lets each side own its index
+/// outright: `push` is the only writer of `tail` and `pop` is the only writer of `head`, so both
+/// can be advanced with a single relaxed/release store and no CAS.
+///
+/// The queue is never used directly; [`SpscQueue::new`] splits it into a [`Producer`] and a
+/// [`Consumer`] handle. The handles are `Send` but not `Clone`, so the single-producer
+/// single-consumer invariant is enforced at the type level.
+///
+/// [`ArrayQueue`]: super::ArrayQueue
+///
+/// # Examples
+///
+/// ```
+/// use crossbeam_queue::SpscQueue;
+///
+/// let (p, c) = SpscQueue::new(2);
+///
+/// assert!(p.push(1).is_ok());
+/// assert!(p.push(2).is_ok());
+/// assert_eq!(p.push(3), Err(3));
+///
+/// assert_eq!(c.pop(), Some(1));
+/// assert_eq!(c.pop(), Some(2));
+/// assert_eq!(c.pop(), None);
+/// ```
+pub struct SpscQueue<T> {
+    /// The slots holding the values.
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+
+    /// The capacity of the queue, always a power of two.
+    capacity: usize,
+
+    /// A bit mask for indexing into `buffer` (`capacity - 1`).
+    mask: usize,
+
+    /// The index of the next slot to pop from, written only by the consumer.
+    head: CachePadded<AtomicUsize>,
+
+    /// The index of the next slot to push into, written only by the producer.
+    tail: CachePadded<AtomicUsize>,
+}
+
+unsafe impl<T: Send> Send for SpscQueue<T> {}
+unsafe impl<T: Send> Sync for SpscQueue<T> {}
+
+impl<T> UnwindSafe for SpscQueue<T> {}
+impl<T> RefUnwindSafe for SpscQueue<T> {}
+
+impl<T> SpscQueue<T> {
+    /// Creates a new single-producer single-consumer queue and returns the two handles.
+    ///
+    /// The `capacity` is rounded up to the next power of two so indexing can use a bit mask. At
+    /// least one slot is always allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::SpscQueue;
+    ///
+    /// let (producer, consumer) = SpscQueue::<i32>::new(100);
+    /// ```
+    // A single queue is inseparable from its producer/consumer pair, so the constructor hands back
+    // both halves rather than a bare `Self`; this is the same shape as `crossbeam-channel`'s
+    // `bounded`/`unbounded`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(capacity: usize) -> (Producer<T>, Consumer<T>) {
+        let capacity = capacity.max(1).next_power_of_two();
+
+        let buffer = (0..capacity)
+            .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+
+        let queue = Arc::new(SpscQueue {
+            buffer,
+            capacity,
+            mask: capacity - 1,
+            head: CachePadded::new(AtomicUsize::new(0)),
+            tail: CachePadded::new(AtomicUsize::new(0)),
+        });
+
+        let producer = Producer {
+            queue: queue.clone(),
+            _marker: PhantomData,
+        };
+        let consumer = Consumer {
+            queue,
+            _marker: PhantomData,
+        };
+        (producer, consumer)
+    }
+
+    /// Returns the capacity of the queue.
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of elements currently in the queue.
+    fn len(&self) -> usize {
+        let tail = self.tail.load(Ordering::Acquire);
+        let head = self.head.load(Ordering::Acquire);
+        tail.wrapping_sub(head)
+    }
+
+    /// Pushes an element into the queue.
+    ///
+    /// Only ever called by the owning [`Producer`], so `tail` can be read relaxed.
+    fn push(&self, value: T) -> Result<(), T> {
+        let tail = self.tail.load(Ordering::Relaxed);
+        let head = self.head.load(Ordering::Acquire);
+
+        // The queue is full if the producer has run a full lap ahead of the consumer.
+        if tail.wrapping_sub(head) == self.capacity {
+            return Err(value);
+        }
+
+        let slot = &self.buffer[tail & self.mask];
+        slot.with_mut(|slot| unsafe { slot.write(MaybeUninit::new(value)) });
+
+        // Publish the write to the consumer.
+        self.tail.store(tail.wrapping_add(1), Ordering::Release);
+        Ok(())
+    }
+
+    /// Pops an element from the queue.
+    ///
+    /// Only ever called by the owning [`Consumer`], so `head` can be read relaxed.
+    fn pop(&self) -> Option<T> {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Acquire);
+
+        // The queue is empty if the consumer has caught up with the producer.
+        if head == tail {
+            return None;
+        }
+
+        let slot = &self.buffer[head & self.mask];
+        let value = slot.with_mut(|slot| unsafe { slot.read().assume_init() });
+
+        // Release the slot back to the producer.
+        self.head.store(head.wrapping_add(1), Ordering::Release);
+        Some(value)
+    }
+}
+
+impl<T> Drop for SpscQueue<T> {
+    fn drop(&mut self) {
+        // Drop any values the handles left behind. No synchronization is needed: both handles are
+        // gone, so we have exclusive access.
+        let mut head = self.head.with_mut(|head| *head);
+        let tail = self.tail.with_mut(|tail| *tail);
+
+        while head != tail {
+            let index = head & self.mask;
+            unsafe {
+                self.buffer[index].with_mut(|slot| (*slot).as_mut_ptr().drop_in_place());
+            }
+            head = head.wrapping_add(1);
+        }
+    }
+}
+
+impl<T> fmt::Debug for SpscQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("SpscQueue { .. }")
+    }
+}
+
+/// The producing half of a [`SpscQueue`].
+///
+/// Created by [`SpscQueue::new`]. This handle is `Send` but deliberately neither `Clone` nor
+/// `Sync`, so at most one thread can ever push into the queue.
+pub struct Producer<T> {
+    queue: Arc<SpscQueue<T>>,
+
+    /// Opts the handle out of `Sync` so a `&Producer` cannot be shared to create a second producer.
+    _marker: PhantomData<Cell<()>>,
+}
+
+unsafe impl<T: Send> Send for Producer<T> {}
+
+impl<T> Producer<T> {
+    /// Attempts to push an element into the queue.
+    ///
+    /// If the queue is full, the element is returned back as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::SpscQueue;
+    ///
+    /// let (p, _c) = SpscQueue::new(1);
+    ///
+    /// assert_eq!(p.push(10), Ok(()));
+    /// assert_eq!(p.push(20), Err(20));
+    /// ```
+    pub fn push(&self, value: T) -> Result<(), T> {
+        self.queue.push(value)
+    }
+
+    /// Returns the capacity of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::SpscQueue;
+    ///
+    /// let (p, _c) = SpscQueue::<i32>::new(100);
+    /// assert_eq!(p.capacity(), 128);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
+    /// Returns the number of elements currently in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::SpscQueue;
+    ///
+    /// let (p, _c) = SpscQueue::new(100);
+    /// assert_eq!(p.len(), 0);
+    ///
+    /// p.push(10).unwrap();
+    /// assert_eq!(p.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the queue is full.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+}
+
+impl<T> fmt::Debug for Producer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("Producer { .. }")
+    }
+}
+
+/// The consuming half of a [`SpscQueue`].
+///
+/// Created by [`SpscQueue::new`]. This handle is `Send` but deliberately neither `Clone` nor
+/// `Sync`, so at most one thread can ever pop from the queue.
+pub struct Consumer<T> {
+    queue: Arc<SpscQueue<T>>,
+
+    /// Opts the handle out of `Sync` so a `&Consumer` cannot be shared to create a second consumer.
+    _marker: PhantomData<Cell<()>>,
+}
+
+unsafe impl<T: Send> Send for Consumer<T> {}
+
+impl<T> Consumer<T> {
+    /// Attempts to pop an element from the queue.
+    ///
+    /// If the queue is empty, `None` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::SpscQueue;
+    ///
+    /// let (p, c) = SpscQueue::new(1);
+    /// p.push(10).unwrap();
+    ///
+    /// assert_eq!(c.pop(), Some(10));
+    /// assert_eq!(c.pop(), None);
+    /// ```
+    pub fn pop(&self) -> Option<T> {
+        self.queue.pop()
+    }
+
+    /// Returns the capacity of the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::SpscQueue;
+    ///
+    /// let (_p, c) = SpscQueue::<i32>::new(100);
+    /// assert_eq!(c.capacity(), 128);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
+    /// Returns the number of elements currently in the queue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossbeam_queue::SpscQueue;
+    ///
+    /// let (p, c) = SpscQueue::new(100);
+    /// p.push(10).unwrap();
+    ///
+    /// assert_eq!(c.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns `true` if the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the queue is full.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+}
+
+impl<T> fmt::Debug for Consumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad("Consumer { .. }")
+    }
+}
@@ -3,6 +3,8 @@
 #![cfg(crossbeam_loom)]
 
 mod array_queue {
+    use core::mem::MaybeUninit;
+
     use crossbeam_queue::ArrayQueue;
 
     use loom_crate::sync::atomic::{AtomicUsize, Ordering};
@@ -268,6 +270,135 @@ mod array_queue {
             .unwrap();
         });
     }
+
+    #[test]
+    fn spsc_push_slice() {
+        #[cfg(miri)]
+        const COUNT: usize = 50;
+        #[cfg(not(miri))]
+        const COUNT: usize = 100_000;
+
+        loom_crate::model(|| {
+            let q = Arc::new(ArrayQueue::new(3));
+
+            spawn({
+                let q = q.clone();
+                move || {
+                    for i in 0..COUNT {
+                        loop {
+                            if let Some(x) = q.pop() {
+                                assert_eq!(x, i);
+                                break;
+                            }
+                        }
+                    }
+                    assert!(q.pop().is_none());
+                }
+            });
+
+            spawn(move || {
+                // Push in batches via `push_slice`, retrying until every element is drained out of
+                // `pending`, while the consumer pops single elements concurrently.
+                let mut pending: Vec<usize> = Vec::new();
+                for i in 0..COUNT {
+                    pending.push(i);
+                    if pending.len() == 4 {
+                        while !pending.is_empty() {
+                            q.push_slice(&mut pending);
+                        }
+                    }
+                }
+                while !pending.is_empty() {
+                    q.push_slice(&mut pending);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn spsc_pop_slice() {
+        #[cfg(miri)]
+        const COUNT: usize = 50;
+        #[cfg(not(miri))]
+        const COUNT: usize = 100_000;
+
+        loom_crate::model(|| {
+            let q = Arc::new(ArrayQueue::new(3));
+
+            spawn({
+                let q = q.clone();
+                move || {
+                    // Drain batches via `pop_slice` while the producer pushes single elements.
+                    let mut got = 0;
+                    let mut buf = [MaybeUninit::uninit(); 4];
+                    while got < COUNT {
+                        let n = q.pop_slice(&mut buf);
+                        for slot in &buf[..n] {
+                            let x = unsafe { slot.assume_init() };
+                            assert_eq!(x, got);
+                            got += 1;
+                        }
+                    }
+                    assert_eq!(q.pop_slice(&mut buf), 0);
+                }
+            });
+
+            spawn(move || {
+                for i in 0..COUNT {
+                    while q.push(i).is_err() {}
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn drops_slice() {
+        // A small, deterministic model: the producer fills the queue with `push_slice` while the
+        // consumer drains an arbitrary prefix with `pop_slice`, then the remainder is dropped with
+        // the queue. Every `DropCounter` must be dropped exactly once.
+        const COUNT: usize = 4;
+
+        loom_crate::model(|| {
+            static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+            #[derive(Debug, PartialEq)]
+            struct DropCounter;
+
+            impl Drop for DropCounter {
+                fn drop(&mut self) {
+                    DROPS.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+
+            DROPS.store(0, Ordering::SeqCst);
+            let q = Arc::new(ArrayQueue::new(COUNT));
+
+            let consumer = spawn({
+                let q = q.clone();
+                move || {
+                    let mut buf: [MaybeUninit<DropCounter>; COUNT] =
+                        core::array::from_fn(|_| MaybeUninit::uninit());
+                    let n = q.pop_slice(&mut buf);
+                    for slot in &mut buf[..n] {
+                        // Move out and drop the popped elements.
+                        unsafe { slot.assume_init_read() };
+                    }
+                }
+            });
+
+            let mut items: Vec<DropCounter> = (0..COUNT).map(|_| DropCounter).collect();
+            while !items.is_empty() {
+                q.push_slice(&mut items);
+            }
+
+            consumer.join().unwrap();
+
+            // Dropping the last handle drops the queue and any elements still buffered in it.
+            drop(q);
+
+            assert_eq!(DROPS.load(Ordering::SeqCst), COUNT);
+        });
+    }
 }
 
 mod seg_queue {
@@ -402,3 +533,83 @@ mod seg_queue {
         });
     }
 }
+
+mod spsc_queue {
+    use crossbeam_queue::SpscQueue;
+
+    use loom_crate::sync::atomic::{AtomicUsize, Ordering};
+    use loom_crate::thread::scope;
+
+    #[test]
+    fn spsc() {
+        #[cfg(miri)]
+        const COUNT: usize = 100;
+        #[cfg(not(miri))]
+        const COUNT: usize = 100_000;
+
+        loom_crate::model(|| {
+            let (p, c) = SpscQueue::new(3);
+
+            scope(|scope| {
+                scope.spawn(move |_| {
+                    for i in 0..COUNT {
+                        loop {
+                            if let Some(x) = c.pop() {
+                                assert_eq!(x, i);
+                                break;
+                            }
+                        }
+                    }
+                    assert!(c.pop().is_none());
+                });
+                scope.spawn(move |_| {
+                    for i in 0..COUNT {
+                        while p.push(i).is_err() {}
+                    }
+                });
+            })
+            .unwrap();
+        });
+    }
+
+    #[test]
+    fn drops() {
+        // A small, deterministic model: the producer fills the queue while the consumer pops an
+        // arbitrary prefix concurrently, then the remaining elements are dropped with the queue.
+        // Every `DropCounter` must be dropped exactly once regardless of the interleaving.
+        const COUNT: usize = 2;
+
+        loom_crate::model(|| {
+            static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+            #[derive(Debug, PartialEq)]
+            struct DropCounter;
+
+            impl Drop for DropCounter {
+                fn drop(&mut self) {
+                    DROPS.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+
+            DROPS.store(0, Ordering::SeqCst);
+            let (p, c) = SpscQueue::new(COUNT);
+
+            scope(|scope| {
+                scope.spawn(move |_| {
+                    // Pop whatever happens to be available; the rest is left for `Drop`.
+                    while c.pop().is_some() {}
+                });
+
+                for _ in 0..COUNT {
+                    while p.push(DropCounter).is_err() {}
+                }
+            })
+            .unwrap();
+
+            // Dropping the last handle drops the queue and any elements still buffered in it.
+            drop(p);
+
+            assert_eq!(DROPS.load(Ordering::SeqCst), COUNT);
+        });
+    }
+}